@@ -222,6 +222,63 @@ class OwnersFieldRequiredError extends AccountError {
   }
 }
 
+class InvalidOwnersError extends AccountError {
+  constructor(
+    message: string,
+    params?: { context?: any; errorType?: string; traceId?: string },
+  ) {
+    super({
+      message: `Invalid owners: ${message}`,
+      ...params,
+    })
+  }
+}
+
+class EmptyOwnerSetError extends InvalidOwnersError {
+  constructor(
+    label: string,
+    params?: { context?: any; errorType?: string; traceId?: string },
+  ) {
+    super(`no ${label}s provided`, params)
+  }
+}
+
+class DuplicateOwnerError extends InvalidOwnersError {
+  constructor(
+    label: string,
+    owner: string,
+    params?: { context?: any; errorType?: string; traceId?: string },
+  ) {
+    super(`duplicate ${label} ${owner}`, { context: { owner }, ...params })
+  }
+}
+
+class InvalidThresholdError extends InvalidOwnersError {
+  constructor(
+    label: string,
+    threshold: number,
+    count: number,
+    params?: { context?: any; errorType?: string; traceId?: string },
+  ) {
+    super(
+      `threshold ${threshold} must be between 1 and the number of ${label}s (${count})`,
+      { context: { threshold, count }, ...params },
+    )
+  }
+}
+
+class InvalidPasskeyPublicKeyError extends InvalidOwnersError {
+  constructor(
+    publicKey: { x: bigint; y: bigint },
+    params?: { context?: any; errorType?: string; traceId?: string },
+  ) {
+    super('passkey public key is not a point on the P-256 curve', {
+      context: { publicKey },
+      ...params,
+    })
+  }
+}
+
 class Eip712DomainNotAvailableError extends AccountError {
   constructor(message: string) {
     super({
@@ -269,4 +326,9 @@ export {
   EoaSigningNotSupportedError,
   EoaSigningMethodNotConfiguredError,
   OwnersFieldRequiredError,
+  InvalidOwnersError,
+  EmptyOwnerSetError,
+  DuplicateOwnerError,
+  InvalidThresholdError,
+  InvalidPasskeyPublicKeyError,
 }
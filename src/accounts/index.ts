@@ -39,14 +39,19 @@ import {
   AccountConfigurationNotSupportedError,
   AccountError,
   DefaultValidatorAlreadyInitializedError,
+  DuplicateOwnerError,
   Eip712DomainNotAvailableError,
   Eip7702AccountMustHaveEoaError,
   Eip7702NotSupportedForAccountError,
+  EmptyOwnerSetError,
   EoaAccountMustHaveAccountError,
   EoaSigningMethodNotConfiguredError,
   EoaSigningNotSupportedError,
   ExistingEip7702AccountsNotSupportedError,
   FactoryArgsNotAvailableError,
+  InvalidOwnersError,
+  InvalidPasskeyPublicKeyError,
+  InvalidThresholdError,
   isAccountError,
   ModuleInstallationNotSupportedError,
   OwnersFieldRequiredError,
@@ -1020,14 +1025,19 @@ export {
   isAccountError,
   AccountError,
   AccountConfigurationNotSupportedError,
+  DuplicateOwnerError,
   Eip712DomainNotAvailableError,
   Eip7702AccountMustHaveEoaError,
   Eip7702NotSupportedForAccountError,
+  EmptyOwnerSetError,
   EoaAccountMustHaveAccountError,
   EoaSigningMethodNotConfiguredError,
   EoaSigningNotSupportedError,
   ExistingEip7702AccountsNotSupportedError,
   FactoryArgsNotAvailableError,
+  InvalidOwnersError,
+  InvalidPasskeyPublicKeyError,
+  InvalidThresholdError,
   ModuleInstallationNotSupportedError,
   OwnersFieldRequiredError,
   SigningNotSupportedForAccountError,
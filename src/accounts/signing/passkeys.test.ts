@@ -36,6 +36,31 @@ describe('Passkeys', () => {
         6705566102199758127831148650668918567109283965479844611524279039128750829560n,
       )
     })
+
+    test('normalizes high s', () => {
+      const parsed = parseSignature(
+        '0xf60f80fe3fd029d1b380d631f0b659bfbc8f8f60921a88e1e123c159aa540f92f12cc8b8b2e447ed23a063c17e667e7a85d95610e1f1b490c6da4a63ec327559',
+      )
+      expect(parsed.r).toEqual(
+        111296353735534357766084082487308191701889898756402773464442570100570127077266n,
+      )
+      expect(parsed.s).toEqual(
+        6705566102199758127831148650668918567109283965479844611524279039128750829560n,
+      )
+    })
+
+    test('rejects out-of-range values', () => {
+      expect(() =>
+        parseSignature(
+          '0x0000000000000000000000000000000000000000000000000000000000000000f12cc8b8b2e447ed23a063c17e667e7a85d95610e1f1b490c6da4a63ec327559',
+        ),
+      ).toThrow('Invalid P-256 signature')
+      expect(() =>
+        parseSignature(
+          '0xf60f80fe3fd029d1b380d631f0b659bfbc8f8f60921a88e1e123c159aa540f92ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551',
+        ),
+      ).toThrow('Invalid P-256 signature')
+    })
   })
 
   describe('Generate Credential ID', () => {
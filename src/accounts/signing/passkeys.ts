@@ -7,6 +7,10 @@ import {
   keccak256,
 } from 'viem'
 
+// P-256 curve order
+const P256_N =
+  0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551n
+
 interface WebAuthnSignature {
   authenticatorData: Hex
   clientDataJSON: string
@@ -37,11 +41,16 @@ function parseSignature(signature: Hex | Uint8Array): {
 } {
   const bytes =
     typeof signature === 'string' ? hexToBytes(signature) : signature
-  const r = bytes.slice(0, 32)
-  const s = bytes.slice(32, 64)
+  const r = BigInt(bytesToHex(bytes.slice(0, 32)))
+  const s = BigInt(bytesToHex(bytes.slice(32, 64)))
+  if (r === 0n || r >= P256_N || s === 0n || s >= P256_N) {
+    throw new Error('Invalid P-256 signature')
+  }
+  // The WebAuthn validator rejects malleable (high-s) signatures, but
+  // authenticators are free to return either form
   return {
-    r: BigInt(bytesToHex(r)),
-    s: BigInt(bytesToHex(s)),
+    r,
+    s: s > P256_N / 2n ? P256_N - s : s,
   }
 }
 
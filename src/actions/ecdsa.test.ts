@@ -3,6 +3,11 @@ import { base } from 'viem/chains'
 import { beforeEach, describe, expect, test, vi } from 'vitest'
 import { accountA, passkeyAccount } from '../../test/consts'
 import { RhinestoneSDK } from '..'
+import {
+  DuplicateOwnerError,
+  EmptyOwnerSetError,
+  InvalidThresholdError,
+} from '../accounts/error'
 import { resolveCallInputs } from '../execution/utils'
 import { OWNABLE_VALIDATOR_ADDRESS } from '../modules/validators/core'
 import {
@@ -166,6 +171,39 @@ describe('ECDSA Actions', () => {
     })
   })
 
+  describe('Enable with an invalid owner set', () => {
+    test('duplicate owners', () => {
+      expect(() => enableEcdsa([MOCK_OWNER_A, MOCK_OWNER_A])).toThrow(
+        DuplicateOwnerError,
+      )
+    })
+
+    test('duplicate owners with different casing', () => {
+      expect(() =>
+        enableEcdsa([
+          MOCK_OWNER_A,
+          `0x${MOCK_OWNER_A.slice(2).toUpperCase()}` as Address,
+        ]),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('zero threshold', () => {
+      expect(() => enableEcdsa([MOCK_OWNER_A], 0)).toThrow(
+        InvalidThresholdError,
+      )
+    })
+
+    test('threshold above owner count', () => {
+      expect(() => enableEcdsa([MOCK_OWNER_A, MOCK_OWNER_B], 3)).toThrow(
+        InvalidThresholdError,
+      )
+    })
+
+    test('no owners', () => {
+      expect(() => enableEcdsa([])).toThrow(EmptyOwnerSetError)
+    })
+  })
+
   describe('Uninstall Ownable Validator', async () => {
     const rhinestone = new RhinestoneSDK({ apiKey: 'test' })
     const rhinestoneAccount = await rhinestone.createAccount({
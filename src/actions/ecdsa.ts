@@ -6,6 +6,7 @@ import {
 import {
   getOwnableValidator,
   OWNABLE_VALIDATOR_ADDRESS,
+  validateOwners,
 } from '../modules/validators/core'
 import type { CalldataInput, LazyCallInput } from '../types'
 
@@ -16,6 +17,7 @@ import type { CalldataInput, LazyCallInput } from '../types'
  * @returns Calls to enable ECDSA authentication
  */
 function enable(owners: Address[], threshold = 1): LazyCallInput {
+  validateOwners(owners, threshold, 'owner')
   const module = getOwnableValidator(threshold, owners)
   return {
     async resolve({ chain, config }) {
@@ -0,0 +1,58 @@
+import { describe, expect, test } from 'vitest'
+import { accountA, accountB, passkeyAccount } from '../../test/consts'
+import {
+  DuplicateOwnerError,
+  EmptyOwnerSetError,
+  InvalidThresholdError,
+} from '../accounts/error'
+import { enable as enableMfa, setSubValidator } from './mfa'
+
+describe('MFA Actions', () => {
+  describe('Enable with an invalid validator set', () => {
+    test('duplicate owners in a sub-validator', () => {
+      expect(() =>
+        enableMfa([{ type: 'ecdsa', accounts: [accountA, accountA] }]),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('zero threshold', () => {
+      expect(() =>
+        enableMfa([{ type: 'ecdsa', accounts: [accountA] }], 0),
+      ).toThrow(InvalidThresholdError)
+    })
+
+    test('threshold above validator count', () => {
+      expect(() =>
+        enableMfa(
+          [
+            { type: 'ecdsa', accounts: [accountA, accountB] },
+            { type: 'passkey', accounts: [passkeyAccount] },
+          ],
+          3,
+        ),
+      ).toThrow(InvalidThresholdError)
+    })
+
+    test('threshold counts only non-null validators', () => {
+      expect(() =>
+        enableMfa([{ type: 'ecdsa', accounts: [accountA] }, null], 2),
+      ).toThrow(InvalidThresholdError)
+    })
+
+    test('no validators', () => {
+      expect(() => enableMfa([null])).toThrow(EmptyOwnerSetError)
+    })
+  })
+
+  describe('Set Sub-Validator', () => {
+    test('rejects a threshold above the owner count', () => {
+      expect(() =>
+        setSubValidator(0, {
+          type: 'ecdsa',
+          accounts: [accountA],
+          threshold: 5,
+        }),
+      ).toThrow(InvalidThresholdError)
+    })
+  })
+})
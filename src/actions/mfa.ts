@@ -7,6 +7,7 @@ import {
   getMultiFactorValidator,
   getValidator,
   MULTI_FACTOR_VALIDATOR_ADDRESS,
+  validateOwnerSet,
 } from '../modules/validators/core'
 import type {
   CalldataInput,
@@ -25,6 +26,11 @@ function enable(
   validators: (OwnableValidatorConfig | WebauthnValidatorConfig | null)[],
   threshold = 1,
 ): LazyCallInput {
+  validateOwnerSet({
+    type: 'multi-factor',
+    validators: validators.filter((validator) => validator !== null),
+    threshold,
+  })
   const module = getMultiFactorValidator(threshold, validators)
   return {
     async resolve({ config }) {
@@ -82,6 +88,7 @@ function setSubValidator(
   id: Hex | number,
   validator: OwnableValidatorConfig | WebauthnValidatorConfig,
 ): CalldataInput {
+  validateOwnerSet(validator)
   const validatorId = padHex(toHex(id), { size: 12 })
   const validatorModule = getValidator(validator)
   return {
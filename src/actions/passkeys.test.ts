@@ -2,8 +2,11 @@ import { base } from 'viem/chains'
 import { describe, expect, test } from 'vitest'
 import { accountA, passkeyAccount } from '../../test/consts'
 import { RhinestoneSDK } from '..'
+import { InvalidPasskeyPublicKeyError } from '../accounts/error'
+import { parsePublicKey } from '../accounts/signing/passkeys'
 import { resolveCallInputs } from '../execution/utils'
 import {
+  addOwner,
   disable as disablePasskeys,
   enable as enablePasskeys,
 } from './passkeys'
@@ -67,4 +70,18 @@ describe('Passkeys Actions', () => {
       ])
     })
   })
+
+  describe('Add Owner', () => {
+    test('accepts a public key on the P-256 curve', () => {
+      const { x, y } = parsePublicKey(passkeyAccount.publicKey)
+      expect(() => addOwner(x, y, false)).not.toThrow()
+    })
+
+    test('rejects a public key off the P-256 curve', () => {
+      const { x, y } = parsePublicKey(passkeyAccount.publicKey)
+      expect(() => addOwner(x, y + 1n, false)).toThrow(
+        InvalidPasskeyPublicKeyError,
+      )
+    })
+  })
 })
@@ -5,6 +5,7 @@ import {
 } from '../accounts'
 import {
   getWebAuthnValidator,
+  validatePublicKey,
   WEBAUTHN_VALIDATOR_ADDRESS,
   type WebauthnCredential,
 } from '../modules/validators/core'
@@ -57,6 +58,7 @@ function addOwner(
   pubKeyY: bigint,
   requireUserVerification: boolean,
 ): CalldataInput {
+  validatePublicKey({ x: pubKeyX, y: pubKeyY })
   return {
     to: WEBAUTHN_VALIDATOR_ADDRESS,
     value: 0n,
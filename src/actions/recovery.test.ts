@@ -1,11 +1,24 @@
 import { createPublicClient } from 'viem'
 import { base } from 'viem/chains'
 import { beforeEach, describe, expect, test, vi } from 'vitest'
-import { accountA, accountB, accountC, accountD } from '../../test/consts'
+import {
+  accountA,
+  accountB,
+  accountC,
+  accountD,
+  passkeyAccount,
+} from '../../test/consts'
 import { RhinestoneSDK } from '..'
+import {
+  DuplicateOwnerError,
+  EmptyOwnerSetError,
+  InvalidThresholdError,
+} from '../accounts/error'
 import { resolveCallInputs } from '../execution/utils'
+import type { OwnableValidatorConfig, WebauthnValidatorConfig } from '../types'
 import {
   recoverEcdsaOwnership as recover,
+  recoverPasskeyOwnership,
   enable as setUpRecovery,
 } from './recovery'
 
@@ -64,6 +77,24 @@ describe('Recovery Actions', () => {
     })
   })
 
+  describe('Set Up Recovery with invalid guardians', () => {
+    test('duplicate guardians', () => {
+      expect(() => setUpRecovery([accountB, accountB])).toThrow(
+        DuplicateOwnerError,
+      )
+    })
+
+    test('threshold above guardian count', () => {
+      expect(() => setUpRecovery([accountB, accountC], 3)).toThrow(
+        InvalidThresholdError,
+      )
+    })
+
+    test('no guardians', () => {
+      expect(() => setUpRecovery([])).toThrow(EmptyOwnerSetError)
+    })
+  })
+
   describe('Recover', () => {
     const rhinestone = new RhinestoneSDK({ apiKey: 'test' })
     const accountPromise = rhinestone.createAccount({
@@ -210,5 +241,53 @@ describe('Recovery Actions', () => {
         },
       ])
     })
+
+    test('rejects an invalid new owner set before reading chain state', async () => {
+      const rhinestoneAccount = await accountPromise
+      const recoverTo = (newOwners: OwnableValidatorConfig) =>
+        recover(accountAddress, newOwners, base, rhinestoneAccount.config)
+      await expect(
+        recoverTo({ type: 'ecdsa', accounts: [accountB], threshold: 0 }),
+      ).rejects.toThrow(InvalidThresholdError)
+      await expect(
+        recoverTo({ type: 'ecdsa', accounts: [accountB], threshold: 2 }),
+      ).rejects.toThrow(InvalidThresholdError)
+      await expect(
+        recoverTo({ type: 'ecdsa', accounts: [accountB, accountB] }),
+      ).rejects.toThrow(DuplicateOwnerError)
+      await expect(recoverTo({ type: 'ecdsa', accounts: [] })).rejects.toThrow(
+        EmptyOwnerSetError,
+      )
+      expect(mockPublicClient.multicall).not.toHaveBeenCalled()
+    })
+
+    test('rejects an invalid new passkey set before reading chain state', async () => {
+      const rhinestoneAccount = await accountPromise
+      const recoverTo = (newOwners: WebauthnValidatorConfig) =>
+        recoverPasskeyOwnership(
+          accountAddress,
+          [],
+          newOwners,
+          base,
+          rhinestoneAccount.config,
+        )
+      await expect(
+        recoverTo({
+          type: 'passkey',
+          accounts: [passkeyAccount],
+          threshold: 2,
+        }),
+      ).rejects.toThrow(InvalidThresholdError)
+      await expect(
+        recoverTo({
+          type: 'passkey',
+          accounts: [passkeyAccount, passkeyAccount],
+        }),
+      ).rejects.toThrow(DuplicateOwnerError)
+      await expect(
+        recoverTo({ type: 'passkey', accounts: [] }),
+      ).rejects.toThrow(EmptyOwnerSetError)
+      expect(mockPublicClient.readContract).not.toHaveBeenCalled()
+    })
   })
 })
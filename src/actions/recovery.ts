@@ -10,6 +10,8 @@ import { resolveCallInputs } from '../execution/utils'
 import {
   getSocialRecoveryValidator,
   OWNABLE_VALIDATOR_ADDRESS,
+  validateOwners,
+  validateOwnerSet,
   WEBAUTHN_VALIDATOR_ADDRESS,
 } from '../modules/validators/core'
 import type {
@@ -34,6 +36,11 @@ import {
  * @returns Calls to set up social recovery
  */
 function enable(guardians: Account[], threshold = 1): LazyCallInput {
+  validateOwners(
+    guardians.map((guardian) => guardian.address),
+    threshold,
+    'guardian',
+  )
   const module = getSocialRecoveryValidator(guardians, threshold)
   return {
     async resolve({ config }) {
@@ -56,6 +63,7 @@ async function recoverEcdsaOwnership(
   chain: Chain,
   config: RhinestoneConfig,
 ): Promise<CalldataInput[]> {
+  validateOwnerSet(newOwners)
   const publicClient = createPublicClient({
     chain,
     transport: createTransport(chain, config.provider),
@@ -177,6 +185,7 @@ async function recoverPasskeyOwnership(
   chain: Chain,
   config: RhinestoneConfig,
 ): Promise<CalldataInput[]> {
+  validateOwnerSet(newOwners)
   const publicClient = createPublicClient({
     chain,
     transport: createTransport(chain, config.provider),
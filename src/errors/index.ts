@@ -1,12 +1,17 @@
 import {
   AccountConfigurationNotSupportedError,
   AccountError,
+  DuplicateOwnerError,
   Eip712DomainNotAvailableError,
   Eip7702AccountMustHaveEoaError,
   Eip7702NotSupportedForAccountError,
+  EmptyOwnerSetError,
   EoaAccountMustHaveAccountError,
   ExistingEip7702AccountsNotSupportedError,
   FactoryArgsNotAvailableError,
+  InvalidOwnersError,
+  InvalidPasskeyPublicKeyError,
+  InvalidThresholdError,
   isAccountError,
   SigningNotSupportedForAccountError,
   SmartSessionsNotEnabledError,
@@ -59,6 +64,11 @@ export {
   isAccountError,
   AccountError,
   AccountConfigurationNotSupportedError,
+  InvalidOwnersError,
+  EmptyOwnerSetError,
+  DuplicateOwnerError,
+  InvalidThresholdError,
+  InvalidPasskeyPublicKeyError,
   Eip712DomainNotAvailableError,
   Eip7702AccountMustHaveEoaError,
   EoaAccountMustHaveAccountError,
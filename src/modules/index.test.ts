@@ -1,5 +1,11 @@
 import { describe, expect, test } from 'vitest'
 import { accountA, accountB, passkeyAccount } from '../../test/consts'
+import {
+  DuplicateOwnerError,
+  EmptyOwnerSetError,
+  InvalidThresholdError,
+} from '../accounts/error'
+import type { Recovery } from '../types'
 import { MODULE_TYPE_ID_FALLBACK, MODULE_TYPE_ID_VALIDATOR } from './common'
 import { getSetup } from './index'
 
@@ -92,6 +98,29 @@ describe('Modules', () => {
       expect(smartSessionFallback.type).toBe(MODULE_TYPE_ID_FALLBACK)
     })
 
+    test('should reject invalid recovery guardians', () => {
+      const getSetupWithRecovery = (recovery: Recovery) =>
+        getSetup({
+          owners: {
+            type: 'ecdsa' as const,
+            accounts: [accountA],
+          },
+          recovery,
+        })
+      expect(() => getSetupWithRecovery({ guardians: [] })).toThrow(
+        EmptyOwnerSetError,
+      )
+      expect(() =>
+        getSetupWithRecovery({ guardians: [accountB, accountB] }),
+      ).toThrow(DuplicateOwnerError)
+      expect(() =>
+        getSetupWithRecovery({ guardians: [accountB], threshold: 0 }),
+      ).toThrow(InvalidThresholdError)
+      expect(() =>
+        getSetupWithRecovery({ guardians: [accountB], threshold: 2 }),
+      ).toThrow(InvalidThresholdError)
+    })
+
     test.todo('using the omni account should install the necessary modules')
   })
 })
@@ -37,7 +37,10 @@ import {
   SMART_SESSION_EMISSARY_ADDRESS,
   WEBAUTHN_VALIDATOR_ADDRESS,
 } from './validators'
-import { getSocialRecoveryValidator } from './validators/core'
+import {
+  getSocialRecoveryValidator,
+  validateOwners,
+} from './validators/core'
 import {
   getSessionDetails,
   signEnableSession,
@@ -55,6 +58,11 @@ function getSetup(config: RhinestoneConfig): ModeleSetup {
     validators.push(smartSessionValidator)
   }
   if (config.recovery) {
+    validateOwners(
+      config.recovery.guardians.map((guardian) => guardian.address),
+      config.recovery.threshold ?? 1,
+      'guardian',
+    )
     const socialRecoveryValidator = getSocialRecoveryValidator(
       config.recovery.guardians,
       config.recovery.threshold,
@@ -1,4 +1,5 @@
-import { decodeAbiParameters, isAddress, size } from 'viem'
+import { type Account, decodeAbiParameters, isAddress, size } from 'viem'
+import { toWebAuthnAccount } from 'viem/account-abstraction'
 import { describe, expect, test } from 'vitest'
 import {
   accountA,
@@ -6,9 +7,20 @@ import {
   accountC,
   passkeyAccount,
 } from '../../../test/consts'
-import { AccountConfigurationNotSupportedError } from '../../accounts/error'
+import {
+  AccountConfigurationNotSupportedError,
+  DuplicateOwnerError,
+  EmptyOwnerSetError,
+  InvalidPasskeyPublicKeyError,
+  InvalidThresholdError,
+} from '../../accounts/error'
 import { MODULE_TYPE_ID_VALIDATOR } from '../common'
-import { getMockSignature, getOwnerValidator, getValidator } from './core'
+import {
+  getMockSignature,
+  getOwnerValidator,
+  getSocialRecoveryValidator,
+  getValidator,
+} from './core'
 
 describe('Validators Core', () => {
   describe('Validator', () => {
@@ -74,6 +86,68 @@ describe('Validators Core', () => {
       )
     })
 
+    test('ENS: owners sorted by address, keeping their expirations', () => {
+      const validator = getValidator({
+        type: 'ens',
+        accounts: [accountA, accountB, accountC],
+        ownerExpirations: [100, 200, 300],
+      })
+      const [threshold, owners] = decodeAbiParameters(
+        [
+          { name: 'threshold', type: 'uint256' },
+          {
+            name: 'owners',
+            type: 'tuple[]',
+            components: [
+              { name: 'addr', type: 'address' },
+              { name: 'expiration', type: 'uint48' },
+            ],
+          },
+        ],
+        validator.initData,
+      )
+      expect(threshold).toEqual(1n)
+      expect(
+        owners.map((owner) => [owner.addr.toLowerCase(), owner.expiration]),
+      ).toEqual([
+        ['0x6092086a3dc0020cd604a68fcf5d430007d51bb7', 200],
+        ['0xc27b7578151c5ef713c62c65db09763d57ac3596', 300],
+        ['0xf6c02c78ded62973b43bfa523b247da099486936', 100],
+      ])
+    })
+
+    test('ECDSA: owner order does not affect initData', () => {
+      const validator = getValidator({
+        type: 'ecdsa',
+        accounts: [accountC, accountA, accountB],
+        threshold: 2,
+      })
+      expect(validator.initData).toEqual(
+        getValidator({
+          type: 'ecdsa',
+          accounts: [accountA, accountB, accountC],
+          threshold: 2,
+        }).initData,
+      )
+    })
+
+    test('Passkey: public key not on the curve', () => {
+      expect(() =>
+        getValidator({
+          type: 'passkey',
+          accounts: [
+            toWebAuthnAccount({
+              credential: {
+                id: passkeyAccount.id,
+                publicKey:
+                  '0x580a9af0569ad3905b26a703201b358aa0904236642ebe79b22a19d00d3737637d46f725a5427ae45a9569259bf67e1e16b187d7b3ad1ed70138c4f0409677d2',
+              },
+            }),
+          ],
+        }),
+      ).toThrow(InvalidPasskeyPublicKeyError)
+    })
+
     const customModule = '0x00000000000000000000000000000000deadbeef'
 
     test('ECDSA: custom module override', () => {
@@ -170,6 +244,32 @@ describe('Validators Core', () => {
     })
   })
 
+  describe('Social Recovery Validator', () => {
+    test('sorts mixed-case guardians in ascending order', () => {
+      const guardians: Account[] = [
+        {
+          address: '0xB000000000000000000000000000000000000004',
+          type: 'json-rpc',
+        },
+        {
+          address: '0xa000000000000000000000000000000000000002',
+          type: 'json-rpc',
+        },
+      ]
+      const validator = getSocialRecoveryValidator(guardians)
+      const [, decodedGuardians] = decodeAbiParameters(
+        [{ type: 'uint256' }, { type: 'address[]' }],
+        validator.initData,
+      )
+      expect(
+        decodedGuardians.map((guardian) => guardian.toLowerCase()),
+      ).toEqual([
+        '0xa000000000000000000000000000000000000002',
+        '0xb000000000000000000000000000000000000004',
+      ])
+    })
+  })
+
   describe('Owner Validator', () => {
     test('ENS owners require an HCA account', () => {
       expect(() =>
@@ -216,5 +316,143 @@ describe('Validators Core', () => {
         '0x5049ecBd4d961aE6DFEED9b7ccCe7f026454970E',
       )
     })
+
+    test('ECDSA: duplicate owners', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'ecdsa',
+            accounts: [accountA, accountB, accountA],
+          },
+        }),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('ENS: duplicate owners', () => {
+      expect(() =>
+        getOwnerValidator({
+          account: { type: 'hca' },
+          owners: {
+            type: 'ens',
+            accounts: [accountA, accountA],
+            ownerExpirations: [281474976710655, 281474976710655],
+          },
+        }),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('Passkey: duplicate credentials', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'passkey',
+            accounts: [passkeyAccount, passkeyAccount],
+          },
+        }),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('Passkey: duplicate credentials with and without the 0x04 prefix', () => {
+      const prefixedPasskeyAccount = toWebAuthnAccount({
+        credential: {
+          id: 'prefixed',
+          publicKey: `0x04${passkeyAccount.publicKey.slice(2)}`,
+        },
+      })
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'passkey',
+            accounts: [passkeyAccount, prefixedPasskeyAccount],
+          },
+        }),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('Multi-factor: duplicate owners in a sub-validator', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'multi-factor',
+            validators: [
+              { type: 'passkey', accounts: [passkeyAccount] },
+              { type: 'ecdsa', accounts: [accountB, accountB] },
+            ],
+          },
+        }),
+      ).toThrow(DuplicateOwnerError)
+    })
+
+    test('ECDSA: threshold above owner count', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'ecdsa',
+            accounts: [accountA, accountB],
+            threshold: 3,
+          },
+        }),
+      ).toThrow(InvalidThresholdError)
+    })
+
+    test('ECDSA: zero threshold', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'ecdsa',
+            accounts: [accountA],
+            threshold: 0,
+          },
+        }),
+      ).toThrow(InvalidThresholdError)
+    })
+
+    test('Multi-factor: threshold above validator count', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'multi-factor',
+            validators: [
+              { type: 'ecdsa', accounts: [accountA] },
+              { type: 'passkey', accounts: [passkeyAccount] },
+            ],
+            threshold: 3,
+          },
+        }),
+      ).toThrow(InvalidThresholdError)
+    })
+
+    test('ECDSA: no owners', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'ecdsa',
+            accounts: [],
+          },
+        }),
+      ).toThrow(EmptyOwnerSetError)
+    })
+
+    test('Passkey: no credentials', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'passkey',
+            accounts: [],
+          },
+        }),
+      ).toThrow(EmptyOwnerSetError)
+    })
+
+    test('Multi-factor: no validators', () => {
+      expect(() =>
+        getOwnerValidator({
+          owners: {
+            type: 'multi-factor',
+            validators: [],
+          },
+        }),
+      ).toThrow(EmptyOwnerSetError)
+    })
   })
 })
@@ -14,6 +14,10 @@ import {
 
 import {
   AccountConfigurationNotSupportedError,
+  DuplicateOwnerError,
+  EmptyOwnerSetError,
+  InvalidPasskeyPublicKeyError,
+  InvalidThresholdError,
   OwnersFieldRequiredError,
 } from '../../accounts/error'
 import type {
@@ -60,6 +64,12 @@ const OWNABLE_BETA_VALIDATOR_ADDRESS: Address =
 const WEBAUTHN_V0_VALIDATOR_ADDRESS: Address =
   '0x0000000000578c4cb0e472a5462da43c495c3f33'
 
+// P-256 field prime and curve coefficient `b` (`a` is -3)
+const P256_P =
+  0xffffffff00000001000000000000000000000000ffffffffffffffffffffffffn
+const P256_B =
+  0x5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604bn
+
 const ECDSA_MOCK_SIGNATURE =
   '0x81d4b4981670cb18f99f0b4a66446df1bf5b204d24cfcb659bf38ba27a4359b5711649ec2423c5e1247245eba2964679b6a1dbb85c992ae40b9b00c6935b02ff1b'
 const WEBAUTHN_MOCK_SIGNATURE =
@@ -88,9 +98,73 @@ function getOwnerValidator(config: RhinestoneAccountConfig) {
       config.account?.type ?? 'nexus',
     )
   }
+  validateOwnerSet(config.owners)
   return getValidator(config.owners)
 }
 
+// Catches owner sets the validator would reject (or misconfigure) on install,
+// before anything is signed or deployed
+function validateOwnerSet(owners: OwnerSet) {
+  switch (owners.type) {
+    case 'ecdsa':
+    case 'ens':
+      validateOwners(
+        owners.accounts.map((account) => account.address),
+        owners.threshold ?? 1,
+        'owner',
+      )
+      break
+    case 'passkey':
+      // Compare on the parsed point, so the same credential in 64-byte and
+      // 65-byte (`0x04`-prefixed) form is caught as a duplicate
+      validateOwners(
+        owners.accounts.map((account) => {
+          const { x, y } = parsePublicKey(account.publicKey)
+          return concat([toHex(x, { size: 32 }), toHex(y, { size: 32 })])
+        }),
+        owners.threshold ?? 1,
+        'passkey',
+      )
+      break
+    case 'multi-factor':
+      if (owners.validators.length === 0) {
+        throw new EmptyOwnerSetError('validator')
+      }
+      validateThreshold(
+        owners.threshold ?? 1,
+        owners.validators.length,
+        'validator',
+      )
+      for (const validator of owners.validators) {
+        validateOwnerSet(validator)
+      }
+      break
+  }
+}
+
+function validateOwners(owners: string[], threshold: number, label: string) {
+  if (owners.length === 0) {
+    throw new EmptyOwnerSetError(label)
+  }
+  const seen = new Set<string>()
+  for (const owner of owners) {
+    const key = owner.toLowerCase()
+    if (seen.has(key)) {
+      throw new DuplicateOwnerError(label, owner)
+    }
+    seen.add(key)
+  }
+  validateThreshold(threshold, owners.length, label)
+}
+
+// A threshold above the signer count can never be met, which locks the
+// validator once installed
+function validateThreshold(threshold: number, count: number, label: string) {
+  if (!Number.isInteger(threshold) || threshold < 1 || threshold > count) {
+    throw new InvalidThresholdError(label, threshold, count)
+  }
+}
+
 function getMockSignature(ownerSet: OwnerSet): Hex {
   switch (ownerSet.type) {
     case 'ecdsa':
@@ -179,6 +253,15 @@ function getValidator(owners: OwnerSet) {
   }
 }
 
+// Owner validators expect owners in ascending order. Lowercase hex compared by
+// code unit matches that and, unlike `localeCompare`, is locale-independent.
+// Checksummed input must be lowercased first, since 'B' sorts before 'a'.
+function compareAddresses(a: Address, b: Address) {
+  const left = a.toLowerCase()
+  const right = b.toLowerCase()
+  return left < right ? -1 : left > right ? 1 : 0
+}
+
 function getOwnableValidator(
   threshold: number,
   owners: Address[],
@@ -193,7 +276,9 @@ function getOwnableValidator(
       ],
       [
         BigInt(threshold),
-        owners.map((owner) => owner.toLowerCase() as Address).sort(),
+        owners
+          .map((owner) => owner.toLowerCase() as Address)
+          .sort(compareAddresses),
       ],
     ),
     deInitData: '0x',
@@ -215,8 +300,10 @@ function getENSValidator(
     expiration: ownerExpirations[index] ?? maxUint48,
   }))
 
-  // Sort by address to match ENS validator's expectations
-  const sortedPairs = ownerPairs.sort((a, b) => a.addr.localeCompare(b.addr))
+  // Same order as the ownable validator
+  const sortedPairs = ownerPairs.sort((a, b) =>
+    compareAddresses(a.addr, b.addr),
+  )
 
   const ownersWithExpiration = sortedPairs
 
@@ -270,6 +357,9 @@ function getWebAuthnValidator(
   }
 
   const publicKeys = webAuthnCredentials.map(getPublicKey)
+  for (const publicKey of publicKeys) {
+    validatePublicKey(publicKey)
+  }
 
   return {
     address: address ?? WEBAUTHN_VALIDATOR_ADDRESS,
@@ -374,7 +464,7 @@ function getSocialRecoveryValidator(
   threshold = 1,
 ): Module {
   const guardianAddresses = guardians.map((guardian) => guardian.address)
-  guardianAddresses.sort()
+  guardianAddresses.sort(compareAddresses)
   return {
     type: MODULE_TYPE_ID_VALIDATOR,
     address: SOCIAL_RECOVERY_VALIDATOR_ADDRESS,
@@ -396,6 +486,21 @@ function getSocialRecoveryValidator(
   }
 }
 
+// A credential off the curve can never produce a valid signature, so
+// installing it would leave the validator unusable for that owner
+function validatePublicKey(publicKey: PublicKey) {
+  const { x, y } = publicKey
+  if (
+    x < 0n ||
+    x >= P256_P ||
+    y < 0n ||
+    y >= P256_P ||
+    (y * y - (x * x * x - 3n * x + P256_B)) % P256_P !== 0n
+  ) {
+    throw new InvalidPasskeyPublicKeyError({ x, y })
+  }
+}
+
 function parsePublicKey(publicKey: Hex | Uint8Array): PublicKey {
   const bytes =
     typeof publicKey === 'string' ? hexToBytes(publicKey) : publicKey
@@ -439,5 +544,8 @@ export {
   getMockSignature,
   supportsEip712,
   ownerSetUsesEns,
+  validateOwnerSet,
+  validateOwners,
+  validatePublicKey,
 }
 export type { WebauthnCredential }
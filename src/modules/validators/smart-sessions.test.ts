@@ -11,6 +11,7 @@ import {
 import { base } from 'viem/chains'
 import { describe, expect, test } from 'vitest'
 import { accountA, accountB } from '../../../test/consts'
+import { DuplicateOwnerError } from '../../accounts/error'
 import type { Session } from '../../types'
 import type { ResolvedSessionSignerSet } from './smart-sessions'
 import {
@@ -166,6 +167,14 @@ describe('getSessionData', () => {
     )
   })
 
+  test('session owner sets are validated', () => {
+    const session: Session = {
+      chain: base,
+      owners: { type: 'ecdsa', accounts: [accountA, accountA] },
+    }
+    expect(() => getSessionData(session)).toThrow(DuplicateOwnerError)
+  })
+
   test('explicit actions → user action + 3 injected (WETH deposit + intent-execution fallback + dummy preclaimop)', () => {
     const data = getSessionData(sessionWithAction)
     expect(data.actions).toHaveLength(4)
@@ -49,6 +49,7 @@ import {
   ownerSetUsesEns,
   SMART_SESSION_EMISSARY_ADDRESS,
   SMART_SESSION_EMISSARY_ADDRESS_DEV,
+  validateOwnerSet,
 } from './core'
 import {
   encodePermit2ClaimPolicyInitData,
@@ -650,6 +651,7 @@ function getSessionData(
   if (ownerSetUsesEns(session.owners)) {
     throw new Error('ENS owners are not supported for smart sessions')
   }
+  validateOwnerSet(session.owners)
   const validator = getValidator(session.owners)
   const allowedContent = [
     {